@@ -4,9 +4,6 @@ use std::io::BufWriter;
 use std::path::Path;
 use std::time::Instant;
 
-type Fsimd = f32x8; // AVX2
-type Msimd = m32x8;
-
 struct ComplexArea {
     cmin_r: f32,
     cmax_r: f32,
@@ -14,8 +11,220 @@ struct ComplexArea {
     cmax_i: f32,
 }
 
-fn mandlebrot_simd(area: &ComplexArea, width: u32, height: u32, image: &mut Vec<u8>) {
+// Bailout radius (squared) for smooth coloring. Needs to be much larger than the
+// classic 4.0 so the escaped |z| is well past the boundary and the log-log term
+// below is well-defined.
+const SMOOTH_BAILOUT: f32 = 65536.0; // 2^16
+
+// How many iterations the palette spans before repeating.
+const SMOOTH_SCALE: f32 = 24.0;
+
+// Control colors the smooth-coloring gradient is built from: deep blue, cyan, white, orange, black.
+const PALETTE: [(f32, f32, f32); 5] = [
+    (0.0, 7.0, 100.0),
+    (32.0, 107.0, 203.0),
+    (237.0, 255.0, 255.0),
+    (255.0, 170.0, 0.0),
+    (0.0, 0.0, 0.0),
+];
+
+// Map a continuous (fractional) iteration count onto the palette by taking `mu`
+// modulo `SMOOTH_SCALE` and linearly interpolating between the two adjacent colors.
+fn palette_color(mu: f32) -> (u8, u8, u8) {
+    let t = mu.rem_euclid(SMOOTH_SCALE) / SMOOTH_SCALE * PALETTE.len() as f32;
+    let i0 = t as usize % PALETTE.len();
+    let i1 = (i0 + 1) % PALETTE.len();
+    let frac = t.fract();
+
+    let (r0, g0, b0) = PALETTE[i0];
+    let (r1, g1, b1) = PALETTE[i1];
+    (
+        (r0 + (r1 - r0) * frac) as u8,
+        (g0 + (g1 - g0) * frac) as u8,
+        (b0 + (b1 - b0) * frac) as u8,
+    )
+}
+
+// CPU feature tiers we can target, ordered from narrowest to widest, mirroring
+// rav1e's `CpuFeatureLevel`. `detect` probes down from the widest instruction
+// set the target supports to the guaranteed scalar fallback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CpuFeatureLevel {
+    Scalar,
+    Sse2,
+    Neon,
+    Avx2,
+    Avx512,
+}
+
+impl CpuFeatureLevel {
+    #[cfg(target_arch = "x86_64")]
+    fn detect() -> Self {
+        if is_x86_feature_detected!("avx512f") {
+            CpuFeatureLevel::Avx512
+        } else if is_x86_feature_detected!("avx2") {
+            CpuFeatureLevel::Avx2
+        } else if is_x86_feature_detected!("sse2") {
+            CpuFeatureLevel::Sse2
+        } else {
+            CpuFeatureLevel::Scalar
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn detect() -> Self {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            CpuFeatureLevel::Neon
+        } else {
+            CpuFeatureLevel::Scalar
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn detect() -> Self {
+        CpuFeatureLevel::Scalar
+    }
+}
+
+// Common operations `mandlebrot_render` needs from a lane of `f32`s, so the
+// kernel can be written once and instantiated at f32x4/f32x8/f32x16.
+trait Simd32:
+    Copy + std::ops::Add<Output = Self> + std::ops::Sub<Output = Self> + std::ops::Mul<Output = Self>
+{
+    type Mask: Copy + std::ops::BitAnd<Output = Self::Mask> + std::ops::Not<Output = Self::Mask>;
+    const LANES: usize;
+
+    fn splat(v: f32) -> Self;
+    fn lane_offsets() -> Self;
+    fn abs(self) -> Self;
+    fn le(self, rhs: Self) -> Self::Mask;
+    fn lt(self, rhs: Self) -> Self::Mask;
+    fn mask_splat(v: bool) -> Self::Mask;
+    fn mask_none(mask: Self::Mask) -> bool;
+    fn select(mask: Self::Mask, a: Self, b: Self) -> Self;
+    fn write_to_slice(self, out: &mut [f32]);
+}
+
+macro_rules! impl_simd32 {
+    ($vec:ty, $mask:ty, $lanes:expr, [$($offset:expr),+]) => {
+        impl Simd32 for $vec {
+            type Mask = $mask;
+            const LANES: usize = $lanes;
+
+            fn splat(v: f32) -> Self {
+                <$vec>::splat(v)
+            }
+
+            fn lane_offsets() -> Self {
+                <$vec>::new($($offset),+)
+            }
+
+            fn abs(self) -> Self {
+                <$vec>::abs(self)
+            }
+
+            fn le(self, rhs: Self) -> Self::Mask {
+                <$vec>::le(self, rhs)
+            }
+
+            fn lt(self, rhs: Self) -> Self::Mask {
+                <$vec>::lt(self, rhs)
+            }
+
+            fn mask_splat(v: bool) -> Self::Mask {
+                <$mask>::splat(v)
+            }
+
+            fn mask_none(mask: Self::Mask) -> bool {
+                mask.none()
+            }
+
+            fn select(mask: Self::Mask, a: Self, b: Self) -> Self {
+                mask.select(a, b)
+            }
+
+            fn write_to_slice(self, out: &mut [f32]) {
+                unsafe { self.write_to_slice_unaligned_unchecked(out) };
+            }
+        }
+    };
+}
+
+impl_simd32!(f32x4, m32x4, 4, [0., 1., 2., 3.]);
+impl_simd32!(f32x8, m32x8, 8, [0., 1., 2., 3., 4., 5., 6., 7.]);
+impl_simd32!(
+    f32x16,
+    m32x16,
+    16,
+    [0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15.]
+);
+
+// Reference-orbit bookkeeping for periodicity detection (as used by FFmpeg's
+// mandelbrot filter): `z` is compared against a reference point refreshed
+// after intervals that double each time (20, 40, 80, ... iterations, i.e. at
+// iterations 20, 60, 140, ...), since a lane/pixel whose orbit
+// returns arbitrarily close to that reference has entered a cycle and can
+// never escape. Generic over `T` so the scalar (`f32`) and SIMD (`V: Simd32`)
+// loops can share the schedule instead of each re-deriving it.
+struct PeriodicityTracker<T> {
+    ref_r: T,
+    ref_i: T,
+    period_interval: usize,
+    next_refresh: usize,
+}
+
+impl<T: Copy> PeriodicityTracker<T> {
+    const INITIAL_INTERVAL: usize = 20;
+
+    fn new(z_r: T, z_i: T) -> Self {
+        PeriodicityTracker {
+            ref_r: z_r,
+            ref_i: z_i,
+            period_interval: Self::INITIAL_INTERVAL,
+            next_refresh: Self::INITIAL_INTERVAL,
+        }
+    }
+
+    fn reference(&self) -> (T, T) {
+        (self.ref_r, self.ref_i)
+    }
+
+    // Refreshes the reference point once `i` reaches the next scheduled
+    // refresh, then doubles the interval before the following one.
+    fn maybe_refresh(&mut self, i: usize, z_r: T, z_i: T) {
+        if i == self.next_refresh {
+            self.ref_r = z_r;
+            self.ref_i = z_i;
+            self.period_interval *= 2;
+            self.next_refresh += self.period_interval;
+        }
+    }
+}
+
+// Vectorized escape-time loop, generic over lane width so the same kernel
+// backs SSE2/NEON (f32x4), AVX2 (f32x8) and AVX-512 (f32x16). `packed_simd`
+// lowers `f32x4`/`m32x4` to NEON instructions on aarch64 the same way it
+// lowers them to SSE2 on x86-64, so no architecture-specific code is needed
+// beyond feature detection and lane-width selection.
+//
+// `write_pixel` is called once per lane with its pixel index, its final
+// iteration count and the `|z|^2` it escaped at (for a lane that never
+// escaped, the last `|z|^2` computed before it finished), so callers can derive either the raw grayscale value or a
+// smooth RGB color from the same loop. `bailout` is caller-supplied since
+// smooth coloring needs a much larger radius than the classic check to keep
+// its log-log term well-defined.
+fn mandlebrot_render<V: Simd32>(
+    area: &ComplexArea,
+    width: u32,
+    height: u32,
+    periodicity: bool,
+    bailout: f32,
+    mut write_pixel: impl FnMut(usize, f32, f32),
+) {
     const MAX_ITER: usize = 256;
+    // How close (in either component) a lane's orbit must return to the
+    // reference point to be considered periodic (see `PeriodicityTracker`).
+    const PERIOD_EPS: f32 = 1e-6;
 
     let cmin_r = area.cmin_r;
     let cmax_r = area.cmax_r;
@@ -24,71 +233,207 @@ fn mandlebrot_simd(area: &ComplexArea, width: u32, height: u32, image: &mut Vec<
 
     let scale_x = (cmax_r - cmin_r) / (width as f32);
     let scale_y = (cmax_i - cmin_i) / (height as f32);
-    let _iter_scale = 255 / MAX_ITER;
 
     for y in 0..height {
-        for x in (0..width).step_by(Fsimd::lanes()) {
-            // Initate vx from x + 0..lanes (max 16)
-            let vx: Fsimd = Fsimd::splat(x as f32)
-                + unsafe {
-                    Fsimd::from_slice_unaligned_unchecked(&[
-                        0., 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13., 14., 15.,
-                    ])
-                };
+        for x in (0..width).step_by(V::LANES) {
+            // Initate vx from x + 0..lanes
+            let vx = V::splat(x as f32) + V::lane_offsets();
 
             // Calculate coordinates based of vx and y
-            let c_r = cmin_r + (vx * scale_x);
-            let c_i = Fsimd::splat(cmax_i - y as f32 * scale_y);
-            // println!("{:?}, {:?}", c_r, c_i);
+            let c_r = V::splat(cmin_r) + vx * V::splat(scale_x);
+            let c_i = V::splat(cmax_i - y as f32 * scale_y);
 
             let mut z_r = c_r;
             let mut z_i = c_i;
 
             // Mask for (a,bi) that are unfinished
-            let mut unfinished = Msimd::splat(true);
-            let mut iter = Fsimd::splat(1.);
+            let mut unfinished = V::mask_splat(true);
+            let mut iter = V::splat(1.);
+            // |z|^2 the moment each lane escapes, frozen once that lane finishes
+            let mut escaped_abs_square = V::splat(bailout);
 
-            for _ in 1..MAX_ITER {
+            let mut tracker = PeriodicityTracker::new(z_r, z_i);
+
+            for i in 1..MAX_ITER {
                 // Mandlebrot calculation thing;
                 let next_z_r = z_r * z_r - z_i * z_i + c_r;
                 let next_z_i = z_r * z_i + z_r * z_i + c_i;
 
                 // Only update to those still unfinished looping
-                z_r = unfinished.select(next_z_r, z_r);
-                z_i = unfinished.select(next_z_i, z_i);
+                z_r = V::select(unfinished, next_z_r, z_r);
+                z_i = V::select(unfinished, next_z_i, z_i);
 
                 // If abs value of Z less than 2, stop iteration
                 let abs_square = z_r * z_r + z_i * z_i;
+                // Capture the escaped magnitude while the lane is still (or just
+                // became) unfinished, before the mask below is updated.
+                escaped_abs_square = V::select(unfinished, abs_square, escaped_abs_square);
                 // Update the unfinished mask
-                unfinished = (abs_square).le(Fsimd::splat(4.0));
-                iter = unfinished.select(iter + 1., iter);
+                unfinished = abs_square.le(V::splat(bailout));
+
+                // Lanes that are still bounded but whose orbit has returned to
+                // (within eps of) the reference point have entered a cycle and
+                // are provably in the set: freeze their iteration count at
+                // MAX_ITER and drop them from `unfinished` so the early-out
+                // below can trigger sooner.
+                if periodicity {
+                    let (ref_r, ref_i) = tracker.reference();
+                    let periodic = (z_r - ref_r).abs().lt(V::splat(PERIOD_EPS))
+                        & (z_i - ref_i).abs().lt(V::splat(PERIOD_EPS));
+                    let cycled = unfinished & periodic;
+                    iter = V::select(cycled, V::splat(MAX_ITER as f32), iter);
+                    unfinished = unfinished & !periodic;
+                }
+
+                iter = V::select(unfinished, iter + V::splat(1.), iter);
+
+                if periodicity {
+                    tracker.maybe_refresh(i, z_r, z_i);
+                }
 
                 // All finished iteration, break loop
-                if unfinished.none() {
+                if V::mask_none(unfinished) {
                     break;
                 }
             }
 
             // Calculate the index in the image for all lanes
-            let index = vx + Fsimd::splat((y * width) as f32);
-            // Transfer the lanes into array at once
-            let mut indexs = [0.0; Fsimd::lanes()];
-            unsafe { index.write_to_slice_unaligned_unchecked(&mut indexs) };
-            // Calculate the color (iter for now 0..256)
-            let color = iter;
-            // 
-            let mut colors = [0.0; Fsimd::lanes()];
-            unsafe { color.write_to_slice_unaligned_unchecked(&mut colors) };
-
-            for lane in 0..Fsimd::lanes() {
-                image[indexs[lane] as usize] = colors[lane] as u8;
+            let index = vx + V::splat((y * width) as f32);
+            // Transfer the lanes into fixed-size stack buffers at once; 16 is
+            // the widest lane count we instantiate (f32x16), so this covers
+            // every `V` without allocating on this hot per-chunk path.
+            let mut indexs = [0.0f32; 16];
+            index.write_to_slice(&mut indexs[..V::LANES]);
+            let mut iters = [0.0f32; 16];
+            iter.write_to_slice(&mut iters[..V::LANES]);
+            let mut abs_squares = [0.0f32; 16];
+            escaped_abs_square.write_to_slice(&mut abs_squares[..V::LANES]);
+
+            for lane in 0..V::LANES {
+                write_pixel(indexs[lane] as usize, iters[lane], abs_squares[lane]);
+            }
+        }
+    }
+}
+
+// Writes the raw (banded) iteration count as an 8-bit grayscale value.
+fn mandlebrot_kernel<V: Simd32>(
+    area: &ComplexArea,
+    width: u32,
+    height: u32,
+    periodicity: bool,
+    image: &mut Vec<u8>,
+) {
+    const BAILOUT: f32 = 4.0;
+    mandlebrot_render::<V>(
+        area,
+        width,
+        height,
+        periodicity,
+        BAILOUT,
+        |pixel, iter, _escaped_abs_square| {
+            image[pixel] = iter as u8;
+        },
+    );
+}
+
+// Same loop as `mandlebrot_kernel`, but derives a continuously-shaded RGB
+// color from the fractional iteration count instead of writing it raw.
+fn mandlebrot_kernel_smooth<V: Simd32>(
+    area: &ComplexArea,
+    width: u32,
+    height: u32,
+    periodicity: bool,
+    image: &mut Vec<u8>,
+) {
+    const MAX_ITER: usize = 256;
+    mandlebrot_render::<V>(
+        area,
+        width,
+        height,
+        periodicity,
+        SMOOTH_BAILOUT,
+        |pixel, iter, escaped_abs_square| {
+            let pixel = pixel * 3;
+            if iter as usize >= MAX_ITER {
+                // Never escaped: point is (assumed) in the set, stays black.
+                image[pixel] = 0;
+                image[pixel + 1] = 0;
+                image[pixel + 2] = 0;
+            } else {
+                let mu = iter + 1. - (escaped_abs_square.sqrt().ln().ln()) / 2f32.ln();
+                let (r, g, b) = palette_color(mu);
+                image[pixel] = r;
+                image[pixel + 1] = g;
+                image[pixel + 2] = b;
             }
+        },
+    );
+}
+
+// Probes the CPU and runs the widest kernel it supports, falling back to the
+// scalar reference loop when no SIMD instruction set is available. Returns
+// the feature level it picked so callers can report it.
+fn mandlebrot_simd(
+    area: &ComplexArea,
+    width: u32,
+    height: u32,
+    periodicity: bool,
+    image: &mut Vec<u8>,
+) -> CpuFeatureLevel {
+    let level = CpuFeatureLevel::detect();
+    match level {
+        CpuFeatureLevel::Avx512 => {
+            mandlebrot_kernel::<f32x16>(area, width, height, periodicity, image)
+        }
+        CpuFeatureLevel::Avx2 => {
+            mandlebrot_kernel::<f32x8>(area, width, height, periodicity, image)
+        }
+        CpuFeatureLevel::Sse2 | CpuFeatureLevel::Neon => {
+            mandlebrot_kernel::<f32x4>(area, width, height, periodicity, image)
+        }
+        CpuFeatureLevel::Scalar => mandlebrot(area, width, height, periodicity, image),
+    }
+    level
+}
+
+// Same dispatch as `mandlebrot_simd`, but runs the smooth/RGB kernel and
+// falls back to the scalar `mandlebrot_smooth` loop when no SIMD instruction
+// set is available.
+fn mandlebrot_simd_smooth(
+    area: &ComplexArea,
+    width: u32,
+    height: u32,
+    periodicity: bool,
+    image: &mut Vec<u8>,
+) -> CpuFeatureLevel {
+    let level = CpuFeatureLevel::detect();
+    match level {
+        CpuFeatureLevel::Avx512 => {
+            mandlebrot_kernel_smooth::<f32x16>(area, width, height, periodicity, image)
+        }
+        CpuFeatureLevel::Avx2 => {
+            mandlebrot_kernel_smooth::<f32x8>(area, width, height, periodicity, image)
+        }
+        CpuFeatureLevel::Sse2 | CpuFeatureLevel::Neon => {
+            mandlebrot_kernel_smooth::<f32x4>(area, width, height, periodicity, image)
         }
+        CpuFeatureLevel::Scalar => mandlebrot_smooth(area, width, height, periodicity, image),
     }
+    level
 }
 
-fn mandlebrot(area: &ComplexArea, width: u32, height: u32, image: &mut Vec<u8>) {
+fn mandlebrot(
+    area: &ComplexArea,
+    width: u32,
+    height: u32,
+    periodicity: bool,
+    image: &mut Vec<u8>,
+) {
     const MAX_ITER: usize = 256;
+    // How close (in either component) the orbit must return to the reference
+    // point to be considered periodic (see `PeriodicityTracker`).
+    const PERIOD_EPS: f32 = 1e-6;
     let cmin_r = area.cmin_r;
     let cmax_r = area.cmax_r;
     let cmin_i = area.cmin_i;
@@ -107,8 +452,9 @@ fn mandlebrot(area: &ComplexArea, width: u32, height: u32, image: &mut Vec<u8>)
             let mut z_i = c_i;
 
             let mut iter = 0;
+            let mut tracker = PeriodicityTracker::new(z_r, z_i);
 
-            for _ in 1..MAX_ITER {
+            for i in 1..MAX_ITER {
                 // Mandlebrot calculation thing;
                 let next_z_r = z_r * z_r - z_i * z_i + c_r;
                 let next_z_i = z_r * z_i + z_r * z_i + c_i;
@@ -119,7 +465,25 @@ fn mandlebrot(area: &ComplexArea, width: u32, height: u32, image: &mut Vec<u8>)
                 if abs_square > 4. {
                     break;
                 }
+
+                if periodicity {
+                    let (ref_r, ref_i) = tracker.reference();
+                    if (z_r - ref_r).abs() < PERIOD_EPS && (z_i - ref_i).abs() < PERIOD_EPS {
+                        // Freeze at the same natural max a non-periodic
+                        // interior point reaches (MAX_ITER - 1): MAX_ITER
+                        // itself truncates to 0 (black) in the `as u8` cast
+                        // below instead of saturating like the SIMD kernel's
+                        // `f32 as u8` does.
+                        iter = MAX_ITER - 1;
+                        break;
+                    }
+                }
+
                 iter += 1;
+
+                if periodicity {
+                    tracker.maybe_refresh(i, z_r, z_i);
+                }
             }
             let index = vx + (y * width) as f32;
             image[index as usize] = iter as u8;
@@ -127,18 +491,147 @@ fn mandlebrot(area: &ComplexArea, width: u32, height: u32, image: &mut Vec<u8>)
     }
 }
 
-fn write_image(path: &Path, image: &Vec<u8>, width: u32, height: u32) {
+// Same scalar reference loop as `mandlebrot`, but writes a continuously-shaded
+// RGB image instead of the raw (banded) iteration count.
+fn mandlebrot_smooth(
+    area: &ComplexArea,
+    width: u32,
+    height: u32,
+    periodicity: bool,
+    image: &mut Vec<u8>,
+) {
+    const MAX_ITER: usize = 256;
+    // How close (in either component) the orbit must return to the reference
+    // point to be considered periodic (see `PeriodicityTracker`).
+    const PERIOD_EPS: f32 = 1e-6;
+    let cmin_r = area.cmin_r;
+    let cmax_r = area.cmax_r;
+    let cmin_i = area.cmin_i;
+    let cmax_i = area.cmax_i;
+    let scale_x = (cmax_r - cmin_r) / (width as f32);
+    let scale_y = (cmax_i - cmin_i) / (height as f32);
+    for y in 0..height {
+        for x in 0..width {
+            let vx = x as f32;
+
+            let c_r = cmin_r + (vx * scale_x);
+            let c_i = cmax_i - y as f32 * scale_y;
+
+            let mut z_r = c_r;
+            let mut z_i = c_i;
+
+            let mut iter = 0;
+            let mut abs_square = z_r * z_r + z_i * z_i;
+            let mut tracker = PeriodicityTracker::new(z_r, z_i);
+
+            for i in 1..MAX_ITER {
+                // Mandlebrot calculation thing;
+                let next_z_r = z_r * z_r - z_i * z_i + c_r;
+                let next_z_i = z_r * z_i + z_r * z_i + c_i;
+                z_r = next_z_r;
+                z_i = next_z_i;
+                abs_square = z_r * z_r + z_i * z_i;
+                if abs_square > SMOOTH_BAILOUT {
+                    break;
+                }
+
+                if periodicity {
+                    let (ref_r, ref_i) = tracker.reference();
+                    if (z_r - ref_r).abs() < PERIOD_EPS && (z_i - ref_i).abs() < PERIOD_EPS {
+                        iter = MAX_ITER;
+                        break;
+                    }
+                }
+
+                iter += 1;
+
+                if periodicity {
+                    tracker.maybe_refresh(i, z_r, z_i);
+                }
+            }
+
+            let index = (vx + (y * width) as f32) as usize * 3;
+            if iter >= MAX_ITER - 1 {
+                image[index] = 0;
+                image[index + 1] = 0;
+                image[index + 2] = 0;
+            } else {
+                let mu = iter as f32 + 1. - (abs_square.sqrt().ln().ln()) / 2f32.ln();
+                let (r, g, b) = palette_color(mu);
+                image[index] = r;
+                image[index + 1] = g;
+                image[index + 2] = b;
+            }
+        }
+    }
+}
+
+fn write_image(path: &Path, image: &Vec<u8>, width: u32, height: u32, color: png::ColorType) {
     let file = File::create(path).unwrap();
     let bufw = &mut BufWriter::new(file);
 
     let mut encoder = png::Encoder::new(bufw, width, height);
     encoder.set_depth(png::BitDepth::Eight);
-    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_color(color);
     let mut writer = encoder.write_header().unwrap();
     writer.write_image_data(&image).unwrap();
 }
 
-#[cfg(target_arch = "x86_64")]
+// Builds the view window around `(center_r, center_i)` for a given `scale`,
+// where `scale` is the width of the view in the complex plane. The height is
+// derived from the image's aspect ratio so pixels stay square.
+fn complex_area_for_scale(
+    center_r: f32,
+    center_i: f32,
+    scale: f32,
+    width: u32,
+    height: u32,
+) -> ComplexArea {
+    let half_width = scale / 2.0;
+    let half_height = half_width * (height as f32 / width as f32);
+    ComplexArea {
+        cmin_r: center_r - half_width,
+        cmax_r: center_r + half_width,
+        cmin_i: center_i - half_height,
+        cmax_i: center_i + half_height,
+    }
+}
+
+// Renders a zoom fly-through into `out_dir` as `frame_0001.png`, `frame_0002.png`, ...
+// The scale is interpolated geometrically (not linearly) between `start_scale`
+// and `end_scale` so the zoom feels constant-speed; the frames can then be
+// stitched into a video externally (e.g. with ffmpeg).
+fn render_zoom_animation(
+    center_r: f32,
+    center_i: f32,
+    start_scale: f32,
+    end_scale: f32,
+    frame_count: u32,
+    width: u32,
+    height: u32,
+    out_dir: &Path,
+) {
+    std::fs::create_dir_all(out_dir).unwrap();
+
+    let mut image: Vec<u8> = vec![0; (width * height) as usize];
+
+    for frame in 0..frame_count {
+        let t = if frame_count <= 1 {
+            0.0
+        } else {
+            frame as f32 / (frame_count - 1) as f32
+        };
+        let scale = start_scale * (end_scale / start_scale).powf(t);
+
+        let area = complex_area_for_scale(center_r, center_i, scale, width, height);
+        mandlebrot_simd(&area, width, height, true, &mut image);
+
+        let path = out_dir.join(format!("frame_{:04}.png", frame + 1));
+        write_image(&path, &image, width, height, png::ColorType::Grayscale);
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
 fn main() {
     const WIDTH: u32 = 1024*4;
     const HEIGHT: u32 = 1024*4;
@@ -151,34 +644,111 @@ fn main() {
         cmax_i: 1.5,
     };
 
-    if is_x86_feature_detected!("avx2") {
-        println!("AVX2 is supported!");
-        println!("Mandlebrot size of {}x{}", WIDTH, HEIGHT);
-
-        let start_time = Instant::now();
-        mandlebrot_simd(&complex_area, WIDTH, HEIGHT, &mut image);
-        let end_time = Instant::now();
-        let elapsed_time = end_time - start_time;
-        println!("SIMD: Time taken (ms): {}", elapsed_time.as_millis());
-        write_image(Path::new(r"mandlebrot_simd.png"), &image, WIDTH, HEIGHT);
-
-        let start_time = Instant::now();
-        mandlebrot(&complex_area, WIDTH, HEIGHT, &mut image);
-        let end_time = Instant::now();
-        let elapsed_time = end_time - start_time;
-        println!("SEQ: Time taken (ms): {}", elapsed_time.as_millis());
-        write_image(
-            Path::new(r"mandlebrot_sequential.png"),
-            &image,
-            WIDTH,
-            HEIGHT,
-        );
-    } else {
-        println!("AVX2 is not supported on this platform.");
-    }
+    println!("Mandlebrot size of {}x{}", WIDTH, HEIGHT);
+
+    let start_time = Instant::now();
+    let level = mandlebrot_simd(&complex_area, WIDTH, HEIGHT, true, &mut image);
+    let end_time = Instant::now();
+    let elapsed_time = end_time - start_time;
+    println!(
+        "SIMD ({:?}): Time taken (ms): {}",
+        level,
+        elapsed_time.as_millis()
+    );
+    write_image(
+        Path::new(r"mandlebrot_simd.png"),
+        &image,
+        WIDTH,
+        HEIGHT,
+        png::ColorType::Grayscale,
+    );
+
+    // Isolate periodicity checking's effect by running the same SIMD kernel
+    // over the same area with it on vs. off, rather than comparing against
+    // the scalar loop below (which differs in more ways than just that).
+    let start_time = Instant::now();
+    mandlebrot_simd(&complex_area, WIDTH, HEIGHT, false, &mut image);
+    let elapsed_no_periodicity = Instant::now() - start_time;
+    let start_time = Instant::now();
+    mandlebrot_simd(&complex_area, WIDTH, HEIGHT, true, &mut image);
+    let elapsed_periodicity = Instant::now() - start_time;
+    println!(
+        "SIMD periodicity off vs on: Time taken (ms): {} vs {}",
+        elapsed_no_periodicity.as_millis(),
+        elapsed_periodicity.as_millis()
+    );
+
+    let start_time = Instant::now();
+    mandlebrot(&complex_area, WIDTH, HEIGHT, true, &mut image);
+    let end_time = Instant::now();
+    let elapsed_time = end_time - start_time;
+    println!("SEQ: Time taken (ms): {}", elapsed_time.as_millis());
+    write_image(
+        Path::new(r"mandlebrot_sequential.png"),
+        &image,
+        WIDTH,
+        HEIGHT,
+        png::ColorType::Grayscale,
+    );
+
+    let mut image_rgb: Vec<u8> = vec![0; (WIDTH * HEIGHT * 3) as usize];
+
+    let start_time = Instant::now();
+    let level = mandlebrot_simd_smooth(&complex_area, WIDTH, HEIGHT, true, &mut image_rgb);
+    let end_time = Instant::now();
+    let elapsed_time = end_time - start_time;
+    println!(
+        "SIMD smooth ({:?}): Time taken (ms): {}",
+        level,
+        elapsed_time.as_millis()
+    );
+    write_image(
+        Path::new(r"mandlebrot_simd_smooth.png"),
+        &image_rgb,
+        WIDTH,
+        HEIGHT,
+        png::ColorType::Rgb,
+    );
+
+    let start_time = Instant::now();
+    mandlebrot_smooth(&complex_area, WIDTH, HEIGHT, true, &mut image_rgb);
+    let end_time = Instant::now();
+    let elapsed_time = end_time - start_time;
+    println!("SEQ smooth: Time taken (ms): {}", elapsed_time.as_millis());
+    write_image(
+        Path::new(r"mandlebrot_sequential_smooth.png"),
+        &image_rgb,
+        WIDTH,
+        HEIGHT,
+        png::ColorType::Rgb,
+    );
+
+    // Zoom fly-through into the "seahorse valley", a region rich in detail.
+    const ANIM_WIDTH: u32 = 512;
+    const ANIM_HEIGHT: u32 = 512;
+    const ANIM_FRAMES: u32 = 60;
+
+    let start_time = Instant::now();
+    render_zoom_animation(
+        -0.75,
+        0.1,
+        3.0,
+        0.001,
+        ANIM_FRAMES,
+        ANIM_WIDTH,
+        ANIM_HEIGHT,
+        Path::new("frames"),
+    );
+    let end_time = Instant::now();
+    let elapsed_time = end_time - start_time;
+    println!(
+        "Zoom animation ({} frames): Time taken (ms): {}",
+        ANIM_FRAMES,
+        elapsed_time.as_millis()
+    );
 }
 
-#[cfg(not(target_arch = "x86_64"))]
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
 fn main() {
-    println!("SIMD and AVX2 are not supported on this platform.");
+    println!("SIMD is not supported on this platform.");
 }